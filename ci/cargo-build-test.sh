@@ -21,6 +21,28 @@ cargo +"$rust_stable" test-bpf -- --nocapture
 cargo +"$rust_stable" build
 cargo +"$rust_stable" test -- --nocapture
 
+# Check that each program builds for CPI callers, with its entrypoint removed
+for program in \
+  associated-token-account/program \
+  examples/rust/cross-program-invocation \
+  examples/rust/custom-heap \
+  examples/rust/logging \
+  examples/rust/shared-memory-invocation \
+  examples/rust/sysvar \
+  examples/rust/transfer-lamports \
+  feature-proposal/program \
+  memo/program \
+  shared-memory/program \
+  stake-pool/program \
+  themis/program_ristretto \
+  token-lending/program \
+  token-swap/program \
+  token/program \
+  token/program-v3 \
+; do
+  cargo +"$rust_stable" test --manifest-path="$program"/Cargo.toml --features cpi --no-run
+done
+
 # Run test-client sanity check
 cargo +"$rust_stable" run --manifest-path=utils/test-client/Cargo.toml
 
@@ -0,0 +1,8 @@
+//! Rust example demonstrating invoking the shared memory program from a
+//! program that links it as a `cpi` dependency
+#![deny(missing_docs)]
+#![forbid(unsafe_code)]
+
+#[cfg(not(feature = "no-entrypoint"))]
+mod entrypoint;
+pub mod processor;
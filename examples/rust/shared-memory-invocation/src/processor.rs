@@ -0,0 +1,41 @@
+//! Program instruction processor
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program::invoke,
+    pubkey::Pubkey,
+};
+
+/// Offset into the shared memory account at which the instruction data is
+/// written
+pub const OFFSET: u64 = 4;
+
+/// Instruction processor
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // Create in iterator to safety reference accounts in the slice
+    let account_info_iter = &mut accounts.iter();
+
+    // Account info for the shared memory program
+    let shared_memory_program_info = next_account_info(account_info_iter)?;
+    // Account info of the shared memory account to write into
+    let shared_memory_info = next_account_info(account_info_iter)?;
+
+    // Invoke the shared memory program to write the instruction data
+    invoke(
+        &spl_shared_memory::instruction::write_pad(
+            shared_memory_program_info.key,
+            shared_memory_info.key,
+            OFFSET,
+            instruction_data,
+        ),
+        &[
+            shared_memory_program_info.clone(), // program being invoked also needs to be included
+            shared_memory_info.clone(),
+        ],
+    )
+}
@@ -0,0 +1,59 @@
+// Mark this test as BPF-only, the shared memory program only exists as a BPF binary
+#![cfg(feature = "test-bpf")]
+
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    rent::Rent,
+};
+use solana_program_test::{processor, BanksClientExt, ProgramTest};
+use solana_sdk::{account::Account, signature::Signer, transaction::Transaction};
+use spl_example_shared_memory_invocation::processor::{process_instruction, OFFSET};
+use std::str::FromStr;
+
+#[tokio::test]
+async fn test_shared_memory_invocation() {
+    let program_id = Pubkey::from_str(&"invoker111111111111111111111111111111111111").unwrap();
+    let shared_memory_pubkey = Pubkey::new_unique();
+    let mut program_test = ProgramTest::new(
+        "spl_example_shared_memory_invocation",
+        program_id,
+        processor!(process_instruction),
+    );
+    program_test.add_program("spl_shared_memory", spl_shared_memory::id(), None);
+    program_test.add_account(
+        shared_memory_pubkey,
+        Account {
+            lamports: Rent::default().minimum_balance(16),
+            data: vec![0; 16],
+            owner: spl_shared_memory::id(),
+            ..Account::default()
+        },
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[Instruction::new(
+            program_id,
+            &[1u8, 2, 3],
+            vec![
+                AccountMeta::new_readonly(spl_shared_memory::id(), false),
+                AccountMeta::new(shared_memory_pubkey, false),
+            ],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    // Shared memory account now holds the instruction data at the offset
+    let shared_memory_account = banks_client
+        .get_account(shared_memory_pubkey)
+        .await
+        .expect("get_account")
+        .expect("shared_memory_account not none");
+    let offset = OFFSET as usize;
+    assert_eq!(shared_memory_account.data[..offset], [0; 4]);
+    assert_eq!(shared_memory_account.data[offset..offset + 3], [1, 2, 3]);
+}
@@ -6,6 +6,14 @@ use solana_program::{
 };
 use std::str::from_utf8;
 
+spl_security_txt::security_txt! {
+    name: "SPL Memo",
+    project_url: "https://spl.solana.com/memo",
+    contacts: "email:security@solana.com",
+    policy: "https://github.com/solana-labs/solana/blob/master/SECURITY.md",
+    source_code: "https://github.com/solana-labs/solana-program-library"
+}
+
 entrypoint!(process_instruction);
 fn process_instruction(
     _program_id: &Pubkey,
@@ -0,0 +1,44 @@
+//! Instruction types
+
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use std::mem::size_of;
+
+/// Creates an instruction that writes `data` into `account` starting at
+/// `offset`.
+///
+/// The instruction data is the little-endian offset followed by the bytes to
+/// write.  `account` must be owned by the shared memory program and be large
+/// enough to hold `offset + data.len()` bytes.
+pub fn write_pad(program_id: &Pubkey, account: &Pubkey, offset: u64, data: &[u8]) -> Instruction {
+    let mut instruction_data = Vec::with_capacity(size_of::<u64>() + data.len());
+    instruction_data.extend_from_slice(&offset.to_le_bytes());
+    instruction_data.extend_from_slice(data);
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![AccountMeta::new(*account, false)],
+        data: instruction_data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_pad() {
+        let program_id = crate::id();
+        let account = Pubkey::new_unique();
+
+        let instruction = write_pad(&program_id, &account, 0x0102, &[7, 8, 9]);
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts, vec![AccountMeta::new(account, false)]);
+        assert_eq!(instruction.data, vec![2, 1, 0, 0, 0, 0, 0, 0, 7, 8, 9]);
+
+        let instruction = write_pad(&program_id, &account, 3, &[]);
+        assert_eq!(instruction.data, 3u64.to_le_bytes().to_vec());
+    }
+}
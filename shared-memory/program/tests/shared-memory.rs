@@ -1,3 +1,6 @@
+// The raw entrypoint is compiled out with `no-entrypoint`
+#![cfg(not(feature = "no-entrypoint"))]
+
 use solana_bpf_loader_program::{
     create_vm,
     serialization::{deserialize_parameters, serialize_parameters},
@@ -8,6 +8,14 @@ use solana_program::{
     program_error::PrintProgramError, pubkey::Pubkey,
 };
 
+spl_security_txt::security_txt! {
+    name: "SPL Stake Pool",
+    project_url: "https://spl.solana.com/stake-pool",
+    contacts: "email:security@solana.com",
+    policy: "https://github.com/solana-labs/solana/blob/master/SECURITY.md",
+    source_code: "https://github.com/solana-labs/solana-program-library"
+}
+
 entrypoint!(process_instruction);
 fn process_instruction(
     program_id: &Pubkey,
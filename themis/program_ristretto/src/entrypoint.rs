@@ -4,6 +4,14 @@ use solana_program::{
     account_info::AccountInfo, entrypoint, entrypoint::ProgramResult, pubkey::Pubkey,
 };
 
+spl_security_txt::security_txt! {
+    name: "SPL THEMIS",
+    project_url: "https://github.com/solana-labs/solana-program-library/tree/master/themis",
+    contacts: "email:security@solana.com",
+    policy: "https://github.com/solana-labs/solana/blob/master/SECURITY.md",
+    source_code: "https://github.com/solana-labs/solana-program-library"
+}
+
 entrypoint!(process_instruction);
 fn process_instruction(
     program_id: &Pubkey,
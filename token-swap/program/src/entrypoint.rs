@@ -6,6 +6,14 @@ use solana_program::{
     program_error::PrintProgramError, pubkey::Pubkey,
 };
 
+spl_security_txt::security_txt! {
+    name: "SPL Token Swap",
+    project_url: "https://spl.solana.com/token-swap",
+    contacts: "email:security@solana.com",
+    policy: "https://github.com/solana-labs/solana/blob/master/SECURITY.md",
+    source_code: "https://github.com/solana-labs/solana-program-library"
+}
+
 entrypoint!(process_instruction);
 fn process_instruction(
     program_id: &Pubkey,
@@ -0,0 +1,267 @@
+//! Decodes accounts owned by the programs in this repository and prints them as JSON
+
+use clap::{crate_description, crate_name, crate_version, value_t, App, Arg};
+use serde_json::{json, Value};
+use solana_clap_utils::{
+    input_parsers::pubkey_of,
+    input_validators::{is_pubkey, is_url},
+};
+use solana_client::rpc_client::RpcClient;
+use solana_program::{program_option::COption, program_pack::Pack, pubkey::Pubkey};
+use spl_feature_proposal::state::FeatureProposal;
+use spl_stake_pool::state::State as StakePoolState;
+use spl_token::state::{Account as TokenAccount, Mint, Multisig};
+use spl_token_swap::state::SwapInfo;
+use std::{fs, process::exit};
+
+type Error = Box<dyn std::error::Error>;
+
+fn pubkey_option(value: &COption<Pubkey>) -> Value {
+    match value {
+        COption::Some(pubkey) => json!(pubkey.to_string()),
+        COption::None => Value::Null,
+    }
+}
+
+fn decode_token(data: &[u8]) -> Result<Value, Error> {
+    // Token accounts carry no type tag, the data length tells them apart
+    match data.len() {
+        TokenAccount::LEN => {
+            let account = TokenAccount::unpack_unchecked(data)?;
+            Ok(json!({
+                "type": "account",
+                "mint": account.mint.to_string(),
+                "owner": account.owner.to_string(),
+                "amount": account.amount,
+                "delegate": pubkey_option(&account.delegate),
+                "state": format!("{:?}", account.state),
+                "isNative": match account.is_native {
+                    COption::Some(rent_exempt_reserve) => json!(rent_exempt_reserve),
+                    COption::None => Value::Null,
+                },
+                "delegatedAmount": account.delegated_amount,
+                "closeAuthority": pubkey_option(&account.close_authority),
+            }))
+        }
+        Mint::LEN => {
+            let mint = Mint::unpack_unchecked(data)?;
+            Ok(json!({
+                "type": "mint",
+                "mintAuthority": pubkey_option(&mint.mint_authority),
+                "supply": mint.supply,
+                "decimals": mint.decimals,
+                "isInitialized": mint.is_initialized,
+                "freezeAuthority": pubkey_option(&mint.freeze_authority),
+            }))
+        }
+        Multisig::LEN => {
+            let multisig = Multisig::unpack_unchecked(data)?;
+            Ok(json!({
+                "type": "multisig",
+                "m": multisig.m,
+                "n": multisig.n,
+                "isInitialized": multisig.is_initialized,
+                "signers": multisig
+                    .signers
+                    .iter()
+                    .take(multisig.n as usize)
+                    .map(|signer| signer.to_string())
+                    .collect::<Vec<_>>(),
+            }))
+        }
+        len => Err(format!("Unexpected token account data length: {}", len).into()),
+    }
+}
+
+fn decode_token_swap(data: &[u8]) -> Result<Value, Error> {
+    let swap_info = SwapInfo::unpack_unchecked(data)?;
+    Ok(json!({
+        "type": "swap",
+        "isInitialized": swap_info.is_initialized,
+        "nonce": swap_info.nonce,
+        "tokenProgramId": swap_info.token_program_id.to_string(),
+        "tokenA": swap_info.token_a.to_string(),
+        "tokenB": swap_info.token_b.to_string(),
+        "poolMint": swap_info.pool_mint.to_string(),
+        "tokenAMint": swap_info.token_a_mint.to_string(),
+        "tokenBMint": swap_info.token_b_mint.to_string(),
+        "poolFeeAccount": swap_info.pool_fee_account.to_string(),
+        "curveType": format!("{:?}", swap_info.swap_curve.curve_type),
+        "curve": format!("{:?}", swap_info.swap_curve.calculator),
+    }))
+}
+
+fn decode_stake_pool(data: &[u8]) -> Result<Value, Error> {
+    match StakePoolState::deserialize(data)? {
+        StakePoolState::Unallocated => Ok(json!({ "type": "unallocated" })),
+        StakePoolState::Init(stake_pool) => Ok(json!({
+            "type": "stakePool",
+            "owner": stake_pool.owner.to_string(),
+            "depositBumpSeed": stake_pool.deposit_bump_seed,
+            "withdrawBumpSeed": stake_pool.withdraw_bump_seed,
+            "poolMint": stake_pool.pool_mint.to_string(),
+            "ownerFeeAccount": stake_pool.owner_fee_account.to_string(),
+            "tokenProgramId": stake_pool.token_program_id.to_string(),
+            "stakeTotal": stake_pool.stake_total,
+            "poolTotal": stake_pool.pool_total,
+            "fee": {
+                "numerator": stake_pool.fee.numerator,
+                "denominator": stake_pool.fee.denominator,
+            },
+        })),
+    }
+}
+
+fn decode_feature_proposal(data: &[u8]) -> Result<Value, Error> {
+    match FeatureProposal::unpack_from_slice(data)? {
+        FeatureProposal::Uninitialized => Ok(json!({ "type": "uninitialized" })),
+        FeatureProposal::Pending(acceptance_criteria) => Ok(json!({
+            "type": "pending",
+            "tokensRequired": acceptance_criteria.tokens_required,
+            "deadline": acceptance_criteria.deadline,
+        })),
+        FeatureProposal::Accepted {
+            tokens_upon_acceptance,
+        } => Ok(json!({
+            "type": "accepted",
+            "tokensUponAcceptance": tokens_upon_acceptance,
+        })),
+        FeatureProposal::Expired => Ok(json!({ "type": "expired" })),
+    }
+}
+
+/// Decodes `data` according to the program that owns the account
+fn decode_account(owner: &Pubkey, data: &[u8]) -> Result<Value, Error> {
+    let (program, parsed) = if *owner == spl_token::id() {
+        ("spl-token", decode_token(data)?)
+    } else if *owner == spl_token_swap::id() {
+        ("spl-token-swap", decode_token_swap(data)?)
+    } else if *owner == spl_stake_pool::id() {
+        ("spl-stake-pool", decode_stake_pool(data)?)
+    } else if *owner == spl_feature_proposal::id() {
+        ("spl-feature-proposal", decode_feature_proposal(data)?)
+    } else {
+        return Err(format!("Account owner {} is not a known SPL program", owner).into());
+    };
+    Ok(json!({
+        "program": program,
+        "parsed": parsed,
+    }))
+}
+
+fn main() {
+    let matches = App::new(crate_name!())
+        .about(crate_description!())
+        .version(crate_version!())
+        .arg({
+            let arg = Arg::with_name("config_file")
+                .short("C")
+                .long("config")
+                .value_name("PATH")
+                .takes_value(true)
+                .help("Configuration file to use");
+            if let Some(ref config_file) = *solana_cli_config::CONFIG_FILE {
+                arg.default_value(&config_file)
+            } else {
+                arg
+            }
+        })
+        .arg(
+            Arg::with_name("json_rpc_url")
+                .long("url")
+                .value_name("URL")
+                .takes_value(true)
+                .validator(is_url)
+                .help("JSON RPC URL for the cluster.  Default from the configuration file."),
+        )
+        .arg(
+            Arg::with_name("address")
+                .value_name("ADDRESS")
+                .validator(is_pubkey)
+                .takes_value(true)
+                .index(1)
+                .required_unless("data")
+                .help("Address of the account to fetch and decode"),
+        )
+        .arg(
+            Arg::with_name("data")
+                .long("data")
+                .value_name("PATH")
+                .takes_value(true)
+                .conflicts_with("address")
+                .requires("owner")
+                .help("Decode the raw account data in this file instead of fetching an account"),
+        )
+        .arg(
+            Arg::with_name("owner")
+                .long("owner")
+                .value_name("PROGRAM_ID")
+                .validator(is_pubkey)
+                .takes_value(true)
+                .requires("data")
+                .help("Program that owns the account data given by --data"),
+        )
+        .get_matches();
+
+    let (owner, data) = if let Some(path) = matches.value_of("data") {
+        let data = fs::read(path).unwrap_or_else(|err| {
+            eprintln!("error: unable to read {}: {}", path, err);
+            exit(1);
+        });
+        (pubkey_of(&matches, "owner").unwrap(), data)
+    } else {
+        let cli_config = if let Some(config_file) = matches.value_of("config_file") {
+            solana_cli_config::Config::load(config_file).unwrap_or_default()
+        } else {
+            solana_cli_config::Config::default()
+        };
+        let json_rpc_url = value_t!(matches, "json_rpc_url", String)
+            .unwrap_or_else(|_| cli_config.json_rpc_url.clone());
+        let address = pubkey_of(&matches, "address").unwrap();
+        let account = RpcClient::new(json_rpc_url)
+            .get_account(&address)
+            .unwrap_or_else(|err| {
+                eprintln!("error: unable to fetch {}: {}", address, err);
+                exit(1);
+            });
+        (account.owner, account.data)
+    };
+
+    match decode_account(&owner, &data) {
+        Ok(decoded) => println!("{}", serde_json::to_string_pretty(&decoded).unwrap()),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_token_mint() {
+        let mint = Mint {
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            supply: 42,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        let mut data = vec![0; Mint::LEN];
+        Mint::pack(mint, &mut data).unwrap();
+
+        let decoded = decode_account(&spl_token::id(), &data).unwrap();
+        assert_eq!(decoded["program"], "spl-token");
+        assert_eq!(decoded["parsed"]["type"], "mint");
+        assert_eq!(decoded["parsed"]["supply"], 42);
+        assert_eq!(decoded["parsed"]["freezeAuthority"], Value::Null);
+    }
+
+    #[test]
+    fn test_decode_unknown_owner() {
+        assert!(decode_account(&Pubkey::new_unique(), &[0; Mint::LEN]).is_err());
+        assert!(decode_account(&spl_token::id(), &[0; 7]).is_err());
+    }
+}
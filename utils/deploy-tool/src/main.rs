@@ -0,0 +1,362 @@
+//! Builds, deploys and verifies the programs in this repository
+//!
+//! Programs are deployed with the `solana deploy` command from the installed
+//! Solana tool suite, which owns them by the BPF loader.  Programs owned by
+//! that loader are final, so a program whose on-chain bytecode differs from
+//! the local build is reported as a mismatch instead of being upgraded.
+
+use clap::{
+    crate_description, crate_name, crate_version, value_t, App, AppSettings, Arg, ArgMatches,
+    SubCommand,
+};
+use solana_clap_utils::input_validators::{is_keypair, is_url};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    bpf_loader,
+    commitment_config::CommitmentConfig,
+    hash::hash,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Signer},
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{exit, Command},
+};
+
+type Error = Box<dyn std::error::Error>;
+type CommandResult = Result<(), Error>;
+
+/// Repository root, manifest paths below are relative to it
+const REPO_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../..");
+
+struct Program {
+    /// Name of the program's shared object, without the `.so` extension
+    name: &'static str,
+    /// Directory of the program's manifest, relative to the repository root
+    manifest_dir: &'static str,
+    /// Address the program is declared at
+    id: fn() -> Pubkey,
+}
+
+const PROGRAMS: &[Program] = &[
+    Program {
+        name: "spl_associated_token_account",
+        manifest_dir: "associated-token-account/program",
+        id: spl_associated_token_account::id,
+    },
+    Program {
+        name: "spl_feature_proposal",
+        manifest_dir: "feature-proposal/program",
+        id: spl_feature_proposal::id,
+    },
+    Program {
+        name: "spl_memo",
+        manifest_dir: "memo/program",
+        id: spl_memo::id,
+    },
+    Program {
+        name: "spl_shared_memory",
+        manifest_dir: "shared-memory/program",
+        id: spl_shared_memory::id,
+    },
+    Program {
+        name: "spl_stake_pool",
+        manifest_dir: "stake-pool/program",
+        id: spl_stake_pool::id,
+    },
+    Program {
+        name: "spl_token",
+        manifest_dir: "token/program",
+        id: spl_token::id,
+    },
+    Program {
+        name: "spl_token_lending",
+        manifest_dir: "token-lending/program",
+        id: spl_token_lending::id,
+    },
+    Program {
+        name: "spl_token_swap",
+        manifest_dir: "token-swap/program",
+        id: spl_token_swap::id,
+    },
+];
+
+struct Config {
+    json_rpc_url: String,
+    fee_payer: String,
+    bpf_out_dir: PathBuf,
+    keypair_dir: Option<PathBuf>,
+    programs: Vec<&'static Program>,
+    commitment_config: CommitmentConfig,
+}
+
+impl Config {
+    fn shared_object(&self, program: &Program) -> PathBuf {
+        self.bpf_out_dir.join(format!("{}.so", program.name))
+    }
+
+    fn program_keypair(&self, program: &Program) -> Option<PathBuf> {
+        self.keypair_dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{}-keypair.json", program.name)))
+    }
+
+    /// Address of `program`, from its keypair if a keypair directory was
+    /// given and from its declared id otherwise
+    fn program_address(&self, program: &Program) -> Result<Pubkey, Error> {
+        match self.program_keypair(program) {
+            Some(path) => Ok(read_keypair_file(&path)
+                .map_err(|err| format!("Unable to read {}: {}", path.display(), err))?
+                .pubkey()),
+            None => Ok((program.id)()),
+        }
+    }
+}
+
+fn run(command: &mut Command) -> CommandResult {
+    println!("Running {:?}", command);
+    let status = command.status()?;
+    if !status.success() {
+        return Err(format!("{:?} failed: {}", command, status).into());
+    }
+    Ok(())
+}
+
+fn command_build(config: &Config) -> CommandResult {
+    for program in &config.programs {
+        run(Command::new("cargo")
+            .arg("build-bpf")
+            .arg("--manifest-path")
+            .arg(
+                Path::new(REPO_ROOT)
+                    .join(program.manifest_dir)
+                    .join("Cargo.toml"),
+            )
+            .arg("--bpf-out-dir")
+            .arg(&config.bpf_out_dir))?;
+    }
+    Ok(())
+}
+
+/// Compares the on-chain bytecode of `program` with the local build,
+/// returning `Ok(false)` if the program has not been deployed
+fn verify_program(
+    rpc_client: &RpcClient,
+    config: &Config,
+    program: &Program,
+) -> Result<bool, Error> {
+    let address = config.program_address(program)?;
+    let path = config.shared_object(program);
+    let local_hash = hash(
+        &fs::read(&path).map_err(|err| format!("Unable to read {}: {}", path.display(), err))?,
+    );
+
+    let account = match rpc_client
+        .get_account_with_commitment(&address, config.commitment_config)?
+        .value
+    {
+        Some(account) => account,
+        None => return Ok(false),
+    };
+    if account.owner != bpf_loader::id() || !account.executable {
+        return Err(format!(
+            "{} at {} is not an executable BPF program",
+            program.name, address
+        )
+        .into());
+    }
+
+    let onchain_hash = hash(&account.data);
+    if onchain_hash != local_hash {
+        return Err(format!(
+            "{} at {} does not match the local build: on-chain {}, local {}",
+            program.name, address, onchain_hash, local_hash
+        )
+        .into());
+    }
+    println!(
+        "{} at {} matches {} ({})",
+        program.name,
+        address,
+        path.display(),
+        local_hash
+    );
+    Ok(true)
+}
+
+fn command_verify(config: &Config) -> CommandResult {
+    let rpc_client = RpcClient::new(config.json_rpc_url.clone());
+    for program in &config.programs {
+        if !verify_program(&rpc_client, config, program)? {
+            return Err(format!(
+                "{} is not deployed at {}",
+                program.name,
+                config.program_address(program)?
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
+
+fn command_deploy(config: &Config) -> CommandResult {
+    let rpc_client = RpcClient::new(config.json_rpc_url.clone());
+    for program in &config.programs {
+        if verify_program(&rpc_client, config, program)? {
+            continue;
+        }
+        // The declared ids are vanity addresses whose keypairs are not
+        // available here, so a fresh deployment needs the program's keypair
+        let program_keypair = config.program_keypair(program).ok_or_else(|| {
+            format!(
+                "{} is not deployed, --program-keypair-dir is required to deploy it",
+                program.name
+            )
+        })?;
+        run(Command::new("solana")
+            .arg("deploy")
+            .arg("--url")
+            .arg(&config.json_rpc_url)
+            .arg("--keypair")
+            .arg(&config.fee_payer)
+            .arg(config.shared_object(program))
+            .arg(program_keypair))?;
+        if !verify_program(&rpc_client, config, program)? {
+            return Err(format!("{} was not deployed", program.name).into());
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    let program_arg = Arg::with_name("program")
+        .long("program")
+        .value_name("NAME")
+        .takes_value(true)
+        .multiple(true)
+        .number_of_values(1)
+        .possible_values(
+            &PROGRAMS
+                .iter()
+                .map(|program| program.name)
+                .collect::<Vec<_>>(),
+        )
+        .help("Program to operate on, may be repeated.  Default all programs");
+    let keypair_dir_arg = Arg::with_name("program_keypair_dir")
+        .long("program-keypair-dir")
+        .value_name("DIR")
+        .takes_value(true)
+        .help("Directory holding a <NAME>-keypair.json for each program, giving its address");
+
+    let matches = App::new(crate_name!())
+        .about(crate_description!())
+        .version(crate_version!())
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg({
+            let arg = Arg::with_name("config_file")
+                .short("C")
+                .long("config")
+                .value_name("PATH")
+                .takes_value(true)
+                .global(true)
+                .help("Configuration file to use");
+            if let Some(ref config_file) = *solana_cli_config::CONFIG_FILE {
+                arg.default_value(&config_file)
+            } else {
+                arg
+            }
+        })
+        .arg(
+            Arg::with_name("json_rpc_url")
+                .long("url")
+                .value_name("URL")
+                .takes_value(true)
+                .global(true)
+                .validator(is_url)
+                .help("JSON RPC URL for the cluster.  Default from the configuration file."),
+        )
+        .arg(
+            Arg::with_name("fee_payer")
+                .long("fee-payer")
+                .value_name("KEYPAIR")
+                .validator(is_keypair)
+                .takes_value(true)
+                .global(true)
+                .help("Keypair that pays for deployments.  Default from the configuration file."),
+        )
+        .arg(
+            Arg::with_name("bpf_out_dir")
+                .long("bpf-out-dir")
+                .value_name("DIR")
+                .takes_value(true)
+                .global(true)
+                .help("Directory of the program shared objects.  Default target/deploy"),
+        )
+        .subcommand(
+            SubCommand::with_name("build")
+                .about("Build the programs")
+                .arg(program_arg.clone()),
+        )
+        .subcommand(
+            SubCommand::with_name("deploy")
+                .about("Deploy the programs that are not yet deployed and verify the others")
+                .arg(program_arg.clone())
+                .arg(keypair_dir_arg.clone()),
+        )
+        .subcommand(
+            SubCommand::with_name("verify")
+                .about("Verify that the deployed programs match the local build")
+                .arg(program_arg)
+                .arg(keypair_dir_arg),
+        )
+        .get_matches();
+
+    let (sub_command, sub_matches) = matches.subcommand();
+    let sub_matches: &ArgMatches = sub_matches.unwrap();
+
+    let config = {
+        let cli_config = if let Some(config_file) = matches.value_of("config_file") {
+            solana_cli_config::Config::load(config_file).unwrap_or_default()
+        } else {
+            solana_cli_config::Config::default()
+        };
+        let programs = match sub_matches.values_of("program") {
+            Some(names) => names
+                .map(|name| {
+                    PROGRAMS
+                        .iter()
+                        .find(|program| program.name == name)
+                        .unwrap()
+                })
+                .collect(),
+            None => PROGRAMS.iter().collect(),
+        };
+        Config {
+            json_rpc_url: value_t!(matches, "json_rpc_url", String)
+                .unwrap_or_else(|_| cli_config.json_rpc_url.clone()),
+            fee_payer: value_t!(matches, "fee_payer", String)
+                .unwrap_or_else(|_| cli_config.keypair_path.clone()),
+            bpf_out_dir: matches
+                .value_of("bpf_out_dir")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| Path::new(REPO_ROOT).join("target/deploy")),
+            keypair_dir: sub_matches
+                .value_of("program_keypair_dir")
+                .map(PathBuf::from),
+            programs,
+            commitment_config: CommitmentConfig::single_gossip(),
+        }
+    };
+
+    let result = match sub_command {
+        "build" => command_build(&config),
+        "deploy" => command_deploy(&config),
+        "verify" => command_verify(&config),
+        _ => unreachable!(),
+    };
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        exit(1);
+    }
+}
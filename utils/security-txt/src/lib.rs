@@ -0,0 +1,92 @@
+#![deny(missing_docs)]
+
+//! Embeds security contact information in on-chain program binaries, so that
+//! anyone holding a deployed program can find out how to report a
+//! vulnerability in it.
+
+/// Marks the start of the embedded security information
+pub const BEGIN_MARKER: &str = "=======BEGIN SECURITY.TXT V1=======\0";
+
+/// Marks the end of the embedded security information
+pub const END_MARKER: &str = "=======END SECURITY.TXT V1=======\0";
+
+/// Embeds the given `field: "value"` pairs in the program binary.
+///
+/// The fields are stored as NUL-terminated strings between `BEGIN_MARKER` and
+/// `END_MARKER`, in a `.security.txt` section when building for BPF.  Invoke
+/// this from the program's entrypoint module so that it is compiled out along
+/// with the entrypoint when the program is used as a dependency.
+#[macro_export]
+macro_rules! security_txt {
+    ($($name:ident: $value:expr),* $(,)?) => {
+        /// Security contact information for this program
+        #[cfg_attr(target_arch = "bpf", link_section = ".security.txt")]
+        #[allow(dead_code, non_upper_case_globals)]
+        #[no_mangle]
+        pub static security_txt: &str = concat!(
+            "=======BEGIN SECURITY.TXT V1=======\0",
+            $(stringify!($name), "\0", $value, "\0",)*
+            "=======END SECURITY.TXT V1=======\0"
+        );
+    };
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Finds the security information embedded by `security_txt!` in a program
+/// binary and returns its `(field, value)` pairs
+pub fn find_security_txt(binary: &[u8]) -> Option<Vec<(String, String)>> {
+    let begin = find(binary, BEGIN_MARKER.as_bytes())? + BEGIN_MARKER.len();
+    let end = begin + find(&binary[begin..], END_MARKER.as_bytes())?;
+    let parts: Vec<String> = binary[begin..end]
+        .split(|byte| *byte == 0)
+        .map(|part| String::from_utf8_lossy(part).into_owned())
+        .collect();
+    // Every field and value is NUL terminated, leaving an empty last part
+    let parts = &parts[..parts.len() - 1];
+    if parts.len() % 2 != 0 {
+        return None;
+    }
+    Some(
+        parts
+            .chunks(2)
+            .map(|pair| (pair[0].clone(), pair[1].clone()))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod embedded {
+        security_txt! {
+            name: "Example",
+            contacts: "email:security@example.com",
+        }
+    }
+
+    #[test]
+    fn test_find_security_txt() {
+        let mut binary = vec![0xff; 16];
+        binary.extend_from_slice(embedded::security_txt.as_bytes());
+        binary.extend_from_slice(&[0xff; 16]);
+
+        assert_eq!(
+            find_security_txt(&binary),
+            Some(vec![
+                ("name".to_string(), "Example".to_string()),
+                (
+                    "contacts".to_string(),
+                    "email:security@example.com".to_string()
+                ),
+            ])
+        );
+        assert_eq!(find_security_txt(&binary[..32]), None);
+        assert_eq!(find_security_txt(&[]), None);
+    }
+}